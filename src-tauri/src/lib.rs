@@ -1,5 +1,10 @@
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use sysinfo::{Components, Disks, Networks, System};
+use tauri::Manager;
 
 // ========== 数据结构 ==========
 
@@ -9,6 +14,27 @@ pub struct CpuInfo {
     usage: f32,
     cores: usize,
     core_usages: Vec<f32>,
+    /// 各逻辑核心当前主频(MHz)。
+    core_mhz: Vec<u64>,
+    /// 第一个核心的当前主频,近似代表整体频率。
+    current_mhz: u64,
+    /// sysinfo 不暴露硬件规格的最低/最高频率,这里用本次采样到的各核心频率取最值做近似。
+    min_mhz: Option<u64>,
+    max_mhz: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct LoadAverage {
+    one: f64,
+    five: f64,
+    fifteen: f64,
+}
+
+#[derive(Serialize)]
+pub struct SwapInfo {
+    total_gb: f64,
+    used_gb: f64,
+    usage_percent: f64,
 }
 
 #[derive(Serialize)]
@@ -27,6 +53,8 @@ pub struct DiskInfo {
     available_gb: f64,
     usage_percent: f64,
     fs_type: String,
+    read_bytes_per_sec: f64,
+    write_bytes_per_sec: f64,
 }
 
 #[derive(Serialize)]
@@ -34,6 +62,8 @@ pub struct NetworkInterface {
     name: String,
     received_bytes: u64,
     transmitted_bytes: u64,
+    rx_bytes_per_sec: f64,
+    tx_bytes_per_sec: f64,
     mac_address: String,
     ip_addresses: Vec<String>,
 }
@@ -44,6 +74,16 @@ pub struct TempInfo {
     temperature: f32,
 }
 
+/// 可选的 GPU 指标,只有启用 `nvidia` feature 且检测到 NVIDIA 设备时才非空。
+#[derive(Serialize)]
+pub struct GpuInfo {
+    name: String,
+    usage_percent: u32,
+    memory_total_mb: u64,
+    memory_used_mb: u64,
+    temperature: u32,
+}
+
 #[derive(Serialize)]
 pub struct ProcessInfo {
     name: String,
@@ -52,6 +92,19 @@ pub struct ProcessInfo {
     memory_mb: f64,
 }
 
+/// `get_processes` 的排序维度,方向和字段编码在同一个枚举里,方便前端直接传一个值。
+#[derive(Deserialize)]
+pub enum SortKey {
+    CpuAsc,
+    CpuDesc,
+    MemoryAsc,
+    MemoryDesc,
+    PidAsc,
+    PidDesc,
+    NameAsc,
+    NameDesc,
+}
+
 #[derive(Serialize)]
 pub struct BatteryInfo {
     percentage: f32,
@@ -70,15 +123,53 @@ pub struct SystemOverview {
     uptime: u64,
 }
 
+/// 调用方想要采集的子系统。不传 `sections`(空列表)时默认等价于全选,
+/// 和旧版一次性返回全部信息的行为保持兼容。
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+    Overview,
+    Cpu,
+    Memory,
+    Swap,
+    LoadAverage,
+    Disks,
+    Networks,
+    Temperatures,
+    Processes,
+    Gpu,
+}
+
+const ALL_SECTIONS: [Section; 10] = [
+    Section::Overview,
+    Section::Cpu,
+    Section::Memory,
+    Section::Swap,
+    Section::LoadAverage,
+    Section::Disks,
+    Section::Networks,
+    Section::Temperatures,
+    Section::Processes,
+    Section::Gpu,
+];
+
 #[derive(Serialize)]
 pub struct FullSystemInfo {
-    overview: SystemOverview,
-    cpu: CpuInfo,
-    memory: MemoryInfo,
-    disks: Vec<DiskInfo>,
-    networks: Vec<NetworkInterface>,
-    temperatures: Vec<TempInfo>,
-    top_processes: Vec<ProcessInfo>,
+    overview: Option<SystemOverview>,
+    cpu: Option<CpuInfo>,
+    memory: Option<MemoryInfo>,
+    swap: Option<SwapInfo>,
+    load_average: Option<LoadAverage>,
+    disks: Option<Vec<DiskInfo>>,
+    networks: Option<Vec<NetworkInterface>>,
+    temperatures: Option<Vec<TempInfo>>,
+    top_processes: Option<Vec<ProcessInfo>>,
+    gpu: Option<Vec<GpuInfo>>,
+}
+
+#[derive(Serialize, Clone, Copy)]
+pub struct HistoryPoint {
+    timestamp_ms: u64,
+    value: f64,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -90,102 +181,436 @@ pub struct PublicIpInfo {
     org: Option<String>,
 }
 
-// ========== 命令 ==========
+// ========== 长驻监控状态 ==========
 
-#[tauri::command]
-fn get_system_info() -> FullSystemInfo {
-    let mut sys = System::new_all();
-    sys.refresh_all();
-    std::thread::sleep(std::time::Duration::from_millis(200));
-    sys.refresh_cpu_usage();
-
-    let cpus = sys.cpus();
-
-    // 磁盘
-    let disks = Disks::new_with_refreshed_list();
-    let disk_list: Vec<DiskInfo> = disks
-        .iter()
-        .map(|d| {
-            let total = d.total_space() as f64 / 1_073_741_824.0;
-            let available = d.available_space() as f64 / 1_073_741_824.0;
-            let used = total - available;
-            DiskInfo {
-                name: d.name().to_string_lossy().to_string(),
-                mount_point: d.mount_point().to_string_lossy().to_string(),
-                total_gb: total,
-                used_gb: used,
-                available_gb: available,
-                usage_percent: if total > 0.0 { used / total * 100.0 } else { 0.0 },
-                fs_type: d.file_system().to_string_lossy().to_string(),
-            }
-        })
-        .collect();
-
-    // 网络接口
-    let networks = Networks::new_with_refreshed_list();
-    let network_list: Vec<NetworkInterface> = networks
-        .iter()
-        .map(|(name, data)| {
-            let ips: Vec<String> = data.ip_networks().iter().map(|ip| ip.addr.to_string()).collect();
-            NetworkInterface {
-                name: name.clone(),
-                received_bytes: data.total_received(),
-                transmitted_bytes: data.total_transmitted(),
-                mac_address: data.mac_address().to_string(),
-                ip_addresses: ips,
+/// 每个指标的历史环形缓冲区最多保留多少个采样点,超出后丢弃最旧的。
+const HISTORY_CAPACITY: usize = 3600;
+
+/// 长驻的采集器：只在应用启动时创建一次，之后每次轮询都走增量 `refresh_*`，
+/// 这样 CPU 使用率的差值就是相对上一次轮询计算的，不需要再靠 sleep 硬等第二个采样点。
+struct Monitor {
+    sys: System,
+    networks: Networks,
+    disks: Disks,
+    components: Components,
+    /// 磁盘/网络各自独立的上一次采样时间点。两个 section 现在可以分开请求,
+    /// 如果共用一个时间戳,只请求其中一个的调用会让另一个的 elapsed 被错误地拉长或缩短。
+    last_disk_sample: Instant,
+    last_network_sample: Instant,
+    /// 每个网卡上一次采样到的 (received, transmitted) 累计字节数。
+    prev_network_bytes: HashMap<String, (u64, u64)>,
+    /// sysinfo 不提供按物理磁盘区分的 IO 计数器，这里用所有进程 `disk_usage()` 的
+    /// 总读写字节数作为系统级磁盘吞吐量的近似值，按上一次采样的差值换算成速率。
+    prev_disk_io_bytes: (u64, u64),
+    /// 按指标名存放的时间序列环形缓冲区，供 `get_history` 查询画图用。
+    history: HashMap<String, VecDeque<HistoryPoint>>,
+    /// `get_processes` 里编译好的正则,和它对应的查询串一起缓存,查询串没变就不用重新编译。
+    cached_regex: Option<(String, Regex)>,
+}
+
+impl Monitor {
+    fn new() -> Self {
+        let mut sys = System::new_all();
+        sys.refresh_all();
+        let prev_disk_io_bytes = total_disk_io_bytes(&sys);
+        Monitor {
+            sys,
+            networks: Networks::new_with_refreshed_list(),
+            disks: Disks::new_with_refreshed_list(),
+            components: Components::new_with_refreshed_list(),
+            last_disk_sample: Instant::now(),
+            last_network_sample: Instant::now(),
+            prev_network_bytes: HashMap::new(),
+            prev_disk_io_bytes,
+            history: HashMap::new(),
+            cached_regex: None,
+        }
+    }
+
+    /// 往某个指标的历史缓冲区里追加一个采样点，超出容量时丢弃最旧的数据。
+    fn push_history(&mut self, metric: &str, point: HistoryPoint) {
+        let buf = self.history.entry(metric.to_string()).or_insert_with(VecDeque::new);
+        buf.push_back(point);
+        if buf.len() > HISTORY_CAPACITY {
+            buf.pop_front();
+        }
+    }
+
+    /// 返回给定查询串编译好的正则,只有查询串变化时才重新编译。
+    fn compiled_regex(&mut self, query: &str) -> Result<&Regex, String> {
+        let stale = match &self.cached_regex {
+            Some((cached_query, _)) => cached_query != query,
+            None => true,
+        };
+        if stale {
+            let regex = Regex::new(query).map_err(|e| format!("无效的正则表达式: {}", e))?;
+            self.cached_regex = Some((query.to_string(), regex));
+        }
+        Ok(&self.cached_regex.as_ref().unwrap().1)
+    }
+}
+
+/// 把所有进程的磁盘读写字节数加总，作为系统级磁盘吞吐量的近似值。
+fn total_disk_io_bytes(sys: &System) -> (u64, u64) {
+    sys.processes().values().fold((0u64, 0u64), |(read, written), p| {
+        let usage = p.disk_usage();
+        (read + usage.total_read_bytes, written + usage.total_written_bytes)
+    })
+}
+
+/// 计算 (current - previous) / elapsed，遇到计数器被重置（current < previous）时返回 0。
+fn rate_per_sec(previous: u64, current: u64, elapsed_secs: f64) -> f64 {
+    if elapsed_secs <= 0.0 || current < previous {
+        return 0.0;
+    }
+    (current - previous) as f64 / elapsed_secs
+}
+
+/// 采集 NVIDIA GPU 指标。没有启用 `nvidia` feature、驱动没装或者没有 NVIDIA 设备时
+/// 都只是返回空列表,不会让 `get_system_info` 报错。
+#[cfg(feature = "nvidia")]
+fn collect_gpu_info() -> Vec<GpuInfo> {
+    use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+    use nvml_wrapper::Nvml;
+
+    let Ok(nvml) = Nvml::init() else {
+        return Vec::new();
+    };
+    let Ok(count) = nvml.device_count() else {
+        return Vec::new();
+    };
+
+    (0..count)
+        .filter_map(|i| nvml.device_by_index(i).ok())
+        .map(|device| {
+            let utilization = device.utilization_rates().ok();
+            let memory = device.memory_info().ok();
+            GpuInfo {
+                name: device.name().unwrap_or_default(),
+                usage_percent: utilization.map(|u| u.gpu).unwrap_or(0),
+                memory_total_mb: memory.as_ref().map(|m| m.total / 1_048_576).unwrap_or(0),
+                memory_used_mb: memory.as_ref().map(|m| m.used / 1_048_576).unwrap_or(0),
+                temperature: device.temperature(TemperatureSensor::Gpu).unwrap_or(0),
             }
         })
-        .collect();
-
-    // 温度
-    let components = Components::new_with_refreshed_list();
-    let temp_list: Vec<TempInfo> = components
-        .iter()
-        .filter(|c| c.temperature().unwrap_or(0.0) > 0.0)
-        .map(|c| TempInfo {
-            label: c.label().to_string(),
-            temperature: c.temperature().unwrap_or(0.0),
-        })
-        .collect();
-
-    // 进程 Top 10 (按 CPU 使用率排序)
-    let mut processes: Vec<ProcessInfo> = sys
-        .processes()
-        .values()
-        .map(|p| ProcessInfo {
-            name: p.name().to_string_lossy().to_string(),
-            pid: p.pid().as_u32(),
-            cpu_usage: p.cpu_usage(),
-            memory_mb: p.memory() as f64 / 1_048_576.0,
-        })
-        .collect();
-    processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
-    processes.truncate(10);
+        .collect()
+}
 
-    FullSystemInfo {
-        overview: SystemOverview {
-            os_name: System::long_os_version().unwrap_or_default(),
-            host_name: System::host_name().unwrap_or_default(),
-            uptime: System::uptime(),
-        },
-        cpu: CpuInfo {
-            name: cpus.first().map(|c| c.brand().to_string()).unwrap_or_default(),
-            usage: sys.global_cpu_usage(),
-            cores: cpus.len(),
-            core_usages: cpus.iter().map(|c| c.cpu_usage()).collect(),
-        },
-        memory: MemoryInfo {
-            total_gb: sys.total_memory() as f64 / 1_073_741_824.0,
-            used_gb: sys.used_memory() as f64 / 1_073_741_824.0,
-            usage_percent: sys.used_memory() as f64 / sys.total_memory() as f64 * 100.0,
+#[cfg(not(feature = "nvidia"))]
+fn collect_gpu_info() -> Vec<GpuInfo> {
+    Vec::new()
+}
+
+fn process_info(p: &sysinfo::Process) -> ProcessInfo {
+    ProcessInfo {
+        name: p.name().to_string_lossy().to_string(),
+        pid: p.pid().as_u32(),
+        cpu_usage: p.cpu_usage(),
+        memory_mb: p.memory() as f64 / 1_048_576.0,
+    }
+}
+
+fn sort_processes(processes: &mut [ProcessInfo], sort_by: SortKey) {
+    match sort_by {
+        SortKey::CpuAsc => {
+            processes.sort_by(|a, b| a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(std::cmp::Ordering::Equal))
+        }
+        SortKey::CpuDesc => {
+            processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal))
+        }
+        SortKey::MemoryAsc => {
+            processes.sort_by(|a, b| a.memory_mb.partial_cmp(&b.memory_mb).unwrap_or(std::cmp::Ordering::Equal))
+        }
+        SortKey::MemoryDesc => {
+            processes.sort_by(|a, b| b.memory_mb.partial_cmp(&a.memory_mb).unwrap_or(std::cmp::Ordering::Equal))
+        }
+        SortKey::PidAsc => processes.sort_by_key(|p| p.pid),
+        SortKey::PidDesc => processes.sort_by_key(|p| std::cmp::Reverse(p.pid)),
+        SortKey::NameAsc => processes.sort_by(|a, b| a.name.cmp(&b.name)),
+        SortKey::NameDesc => processes.sort_by(|a, b| b.name.cmp(&a.name)),
+    }
+}
+
+// ========== 命令 ==========
+
+#[tauri::command]
+fn get_system_info(monitor: tauri::State<Mutex<Monitor>>, sections: Option<Vec<Section>>) -> FullSystemInfo {
+    let sections = sections.unwrap_or_default();
+    let wanted: HashSet<Section> = if sections.is_empty() {
+        ALL_SECTIONS.iter().copied().collect()
+    } else {
+        sections.into_iter().collect()
+    };
+
+    let mut monitor = monitor.lock().unwrap();
+    let monitor = &mut *monitor;
+
+    let want_cpu = wanted.contains(&Section::Cpu);
+    let want_memory = wanted.contains(&Section::Memory) || wanted.contains(&Section::Swap);
+    let want_disks = wanted.contains(&Section::Disks);
+    let want_networks = wanted.contains(&Section::Networks);
+    let want_temperatures = wanted.contains(&Section::Temperatures);
+    let want_processes = wanted.contains(&Section::Processes);
+    // 磁盘吞吐量是从进程表的 disk_usage() 汇总出来的(见 total_disk_io_bytes),
+    // 所以只要请求了 Disks 就必须一起刷新进程表,否则算出来的速率会对着一份陈旧快照算。
+    let want_process_refresh = want_processes || want_disks;
+
+    // 只刷新被请求的子系统,磁盘/温度/全量进程表这几项最费时,不用的面板不用替它们买单。
+    if want_cpu {
+        monitor.sys.refresh_cpu_usage();
+        monitor.sys.refresh_cpu_frequency();
+    }
+    if want_memory {
+        monitor.sys.refresh_memory();
+    }
+    if want_process_refresh {
+        monitor
+            .sys
+            .refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+    }
+    if want_disks {
+        monitor.disks.refresh(true);
+    }
+    if want_networks {
+        monitor.networks.refresh(true);
+    }
+    if want_temperatures {
+        monitor.components.refresh(true);
+    }
+
+    let now = Instant::now();
+    let timestamp_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    let disks = want_disks.then(|| {
+        // 磁盘/网络的采样时钟是分开的(见 Monitor::last_disk_sample),这样只请求其中一个
+        // section 的调用不会让另一个的 elapsed 被错误地拉长。
+        let elapsed_secs = now.duration_since(monitor.last_disk_sample).as_secs_f64();
+        monitor.last_disk_sample = now;
+
+        // 磁盘:sysinfo 不暴露按盘的 IO 计数器,这里复用所有进程的磁盘吞吐量近似值
+        let current_disk_io = total_disk_io_bytes(&monitor.sys);
+        let read_bytes_per_sec = rate_per_sec(monitor.prev_disk_io_bytes.0, current_disk_io.0, elapsed_secs);
+        let write_bytes_per_sec = rate_per_sec(monitor.prev_disk_io_bytes.1, current_disk_io.1, elapsed_secs);
+        monitor.prev_disk_io_bytes = current_disk_io;
+
+        monitor
+            .disks
+            .iter()
+            .map(|d| {
+                let total = d.total_space() as f64 / 1_073_741_824.0;
+                let available = d.available_space() as f64 / 1_073_741_824.0;
+                let used = total - available;
+                DiskInfo {
+                    name: d.name().to_string_lossy().to_string(),
+                    mount_point: d.mount_point().to_string_lossy().to_string(),
+                    total_gb: total,
+                    used_gb: used,
+                    available_gb: available,
+                    usage_percent: if total > 0.0 { used / total * 100.0 } else { 0.0 },
+                    fs_type: d.file_system().to_string_lossy().to_string(),
+                    read_bytes_per_sec,
+                    write_bytes_per_sec,
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let networks = want_networks.then(|| {
+        let elapsed_secs = now.duration_since(monitor.last_network_sample).as_secs_f64();
+        monitor.last_network_sample = now;
+
+        let network_list: Vec<NetworkInterface> = monitor
+            .networks
+            .iter()
+            .map(|(name, data)| {
+                let ips: Vec<String> = data.ip_networks().iter().map(|ip| ip.addr.to_string()).collect();
+                let received = data.total_received();
+                let transmitted = data.total_transmitted();
+                let (prev_rx, prev_tx) = monitor
+                    .prev_network_bytes
+                    .get(name)
+                    .copied()
+                    .unwrap_or((received, transmitted));
+                NetworkInterface {
+                    name: name.clone(),
+                    received_bytes: received,
+                    transmitted_bytes: transmitted,
+                    rx_bytes_per_sec: rate_per_sec(prev_rx, received, elapsed_secs),
+                    tx_bytes_per_sec: rate_per_sec(prev_tx, transmitted, elapsed_secs),
+                    mac_address: data.mac_address().to_string(),
+                    ip_addresses: ips,
+                }
+            })
+            .collect();
+        monitor.prev_network_bytes = monitor
+            .networks
+            .iter()
+            .map(|(name, data)| (name.clone(), (data.total_received(), data.total_transmitted())))
+            .collect();
+
+        let total_rx_per_sec: f64 = network_list.iter().map(|n| n.rx_bytes_per_sec).sum();
+        let total_tx_per_sec: f64 = network_list.iter().map(|n| n.tx_bytes_per_sec).sum();
+        monitor.push_history("net_rx", HistoryPoint { timestamp_ms, value: total_rx_per_sec });
+        monitor.push_history("net_tx", HistoryPoint { timestamp_ms, value: total_tx_per_sec });
+
+        network_list
+    });
+
+    let temperatures = want_temperatures.then(|| {
+        monitor
+            .components
+            .iter()
+            .filter(|c| c.temperature().unwrap_or(0.0) > 0.0)
+            .map(|c| TempInfo {
+                label: c.label().to_string(),
+                temperature: c.temperature().unwrap_or(0.0),
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let top_processes = want_processes.then(|| {
+        let mut processes: Vec<ProcessInfo> = monitor.sys.processes().values().map(process_info).collect();
+        processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
+        processes.truncate(10);
+        processes
+    });
+
+    let cpu = want_cpu.then(|| {
+        let cpus = monitor.sys.cpus();
+        let cpu_name = cpus.first().map(|c| c.brand().to_string()).unwrap_or_default();
+        let cores_len = cpus.len();
+        let global_cpu_usage = monitor.sys.global_cpu_usage();
+        let core_usages: Vec<f32> = cpus.iter().map(|c| c.cpu_usage()).collect();
+        let core_mhz: Vec<u64> = cpus.iter().map(|c| c.frequency()).collect();
+        let current_mhz = core_mhz.first().copied().unwrap_or(0);
+        let min_mhz = core_mhz.iter().copied().filter(|mhz| *mhz > 0).min();
+        let max_mhz = core_mhz.iter().copied().filter(|mhz| *mhz > 0).max();
+
+        monitor.push_history("cpu", HistoryPoint { timestamp_ms, value: global_cpu_usage as f64 });
+        for (i, usage) in core_usages.iter().enumerate() {
+            monitor.push_history(&format!("cpu{i}"), HistoryPoint { timestamp_ms, value: *usage as f64 });
+        }
+
+        CpuInfo {
+            name: cpu_name,
+            usage: global_cpu_usage,
+            cores: cores_len,
+            core_usages,
+            core_mhz,
+            current_mhz,
+            min_mhz,
+            max_mhz,
+        }
+    });
+
+    let memory = wanted.contains(&Section::Memory).then(|| {
+        let usage_percent = monitor.sys.used_memory() as f64 / monitor.sys.total_memory() as f64 * 100.0;
+        monitor.push_history("memory", HistoryPoint { timestamp_ms, value: usage_percent });
+        MemoryInfo {
+            total_gb: monitor.sys.total_memory() as f64 / 1_073_741_824.0,
+            used_gb: monitor.sys.used_memory() as f64 / 1_073_741_824.0,
+            usage_percent,
+        }
+    });
+
+    let swap = wanted.contains(&Section::Swap).then(|| SwapInfo {
+        total_gb: monitor.sys.total_swap() as f64 / 1_073_741_824.0,
+        used_gb: monitor.sys.used_swap() as f64 / 1_073_741_824.0,
+        usage_percent: if monitor.sys.total_swap() > 0 {
+            monitor.sys.used_swap() as f64 / monitor.sys.total_swap() as f64 * 100.0
+        } else {
+            0.0
         },
-        disks: disk_list,
-        networks: network_list,
-        temperatures: temp_list,
-        top_processes: processes,
+    });
+
+    let load_average = wanted.contains(&Section::LoadAverage).then(|| {
+        let load = System::load_average();
+        LoadAverage { one: load.one, five: load.five, fifteen: load.fifteen }
+    });
+
+    let overview = wanted.contains(&Section::Overview).then(|| SystemOverview {
+        os_name: System::long_os_version().unwrap_or_default(),
+        host_name: System::host_name().unwrap_or_default(),
+        uptime: System::uptime(),
+    });
+
+    let gpu = wanted.contains(&Section::Gpu).then(collect_gpu_info);
+
+    FullSystemInfo {
+        overview,
+        cpu,
+        memory,
+        swap,
+        load_average,
+        disks,
+        networks,
+        temperatures,
+        top_processes,
+        gpu,
     }
 }
 
+/// 查询某个指标最近 `points` 个历史采样点,供前端绘制趋势图。
+/// 不同指标、不同调用可以各自请求不同的窗口长度(独立缩放)。
+#[tauri::command]
+fn get_history(monitor: tauri::State<Mutex<Monitor>>, metric: String, points: usize) -> Vec<HistoryPoint> {
+    let monitor = monitor.lock().unwrap();
+    monitor
+        .history
+        .get(&metric)
+        .map(|buf| {
+            let skip = buf.len().saturating_sub(points);
+            buf.iter().skip(skip).copied().collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 返回完整的(或按 `limit` 截断的)进程列表,支持按名称过滤和多种排序方式。
+/// `query` 为空时不过滤;`use_regex` 为 true 时把 `query` 当正则,否则做大小写不敏感的子串匹配。
+#[tauri::command]
+fn get_processes(
+    monitor: tauri::State<Mutex<Monitor>>,
+    query: Option<String>,
+    use_regex: bool,
+    sort_by: SortKey,
+    limit: Option<usize>,
+) -> Result<Vec<ProcessInfo>, String> {
+    let mut monitor = monitor.lock().unwrap();
+    let monitor = &mut *monitor;
+    monitor.sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+    let query = query.unwrap_or_default();
+    let mut processes: Vec<ProcessInfo> = if use_regex && !query.is_empty() {
+        // 先把缓存的 Regex 克隆出来,结束对 monitor 的可变借用,下面才能再借用 monitor.sys。
+        let regex = monitor.compiled_regex(&query)?.clone();
+        monitor
+            .sys
+            .processes()
+            .values()
+            .filter(|p| regex.is_match(&p.name().to_string_lossy()))
+            .map(process_info)
+            .collect()
+    } else {
+        let needle = query.to_lowercase();
+        monitor
+            .sys
+            .processes()
+            .values()
+            .filter(|p| needle.is_empty() || p.name().to_string_lossy().to_lowercase().contains(&needle))
+            .map(process_info)
+            .collect()
+    };
+
+    sort_processes(&mut processes, sort_by);
+    if let Some(limit) = limit {
+        processes.truncate(limit);
+    }
+    Ok(processes)
+}
+
 #[tauri::command]
 async fn get_public_ip() -> Result<PublicIpInfo, String> {
     let resp = reqwest::get("https://ipinfo.io/json")
@@ -226,11 +651,17 @@ fn get_battery_info() -> Option<BatteryInfo> {
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .setup(|app| {
+            app.manage(Mutex::new(Monitor::new()));
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             get_system_info,
+            get_history,
+            get_processes,
             get_public_ip,
             get_battery_info
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
-}
\ No newline at end of file
+}